@@ -1,3 +1,6 @@
+#[cfg(not(mobile))]
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
 #[cfg(not(mobile))]
 use std::sync::Mutex;
 
@@ -5,7 +8,7 @@ use std::sync::Mutex;
 use std::{env, fs};
 
 #[cfg(not(mobile))]
-use tauri::{Manager, RunEvent, WindowEvent};
+use tauri::{Emitter, Manager, RunEvent, WindowEvent};
 
 #[cfg(not(mobile))]
 use tauri_plugin_shell::{process::CommandChild, ShellExt};
@@ -13,12 +16,120 @@ use tauri_plugin_shell::{process::CommandChild, ShellExt};
 #[cfg(all(not(mobile), windows))]
 use std::process::{Command, Stdio};
 
+/// Shared state for the bundled sidecar process and its supervisor.
+#[cfg(not(mobile))]
+struct SidecarState {
+    /// The running child, if any.
+    child: Mutex<Option<CommandChild>>,
+    /// Set when the app is tearing the sidecar down on purpose (exit, window
+    /// close, explicit stop) so the supervisor doesn't treat it as a crash.
+    shutting_down: AtomicBool,
+    /// Bumped on every [`spawn_sidecar`] call; a supervisor task whose
+    /// generation no longer matches has been superseded and exits.
+    generation: AtomicU64,
+    /// Path of the sidecar log file, recorded at spawn for diagnostics.
+    log_path: Mutex<Option<std::path::PathBuf>>,
+}
+
+#[cfg(not(mobile))]
+impl SidecarState {
+    fn new() -> Self {
+        SidecarState {
+            child: Mutex::new(None),
+            shutting_down: AtomicBool::new(false),
+            generation: AtomicU64::new(0),
+            log_path: Mutex::new(None),
+        }
+    }
+}
+
+/// Host and port the sidecar web server binds to. Shared between the spawned
+/// process's environment and the readiness probe so the two can't drift.
+#[cfg(not(mobile))]
+const SIDECAR_HOST: &str = "127.0.0.1";
 #[cfg(not(mobile))]
-struct SidecarState(Mutex<Option<CommandChild>>);
+const SIDECAR_PORT: u16 = 8765;
+
+/// How long to wait for the sidecar to start accepting connections, and how
+/// often to poll while waiting.
+#[cfg(not(mobile))]
+const READINESS_TIMEOUT_MS: u128 = 30_000;
+#[cfg(not(mobile))]
+const READINESS_INTERVAL_MS: u64 = 250;
+
+/// Backoff bounds and stability threshold for the sidecar supervisor.
+#[cfg(not(mobile))]
+const SUPERVISOR_MIN_BACKOFF_MS: u64 = 1_000;
+#[cfg(not(mobile))]
+const SUPERVISOR_MAX_BACKOFF_MS: u64 = 30_000;
+/// If the child stays up at least this long, the backoff delay is reset.
+#[cfg(not(mobile))]
+const SUPERVISOR_STABLE_MS: u128 = 60_000;
+/// How many consecutive failed restarts before the supervisor gives up.
+#[cfg(not(mobile))]
+const SUPERVISOR_MAX_ATTEMPTS: u32 = 10;
+
+/// A single line of sidecar output, forwarded to the webview as a
+/// `sidecar-log` event so the frontend can render a live log console.
+#[cfg(not(mobile))]
+#[derive(Clone, serde::Serialize)]
+struct SidecarLogEvent {
+    /// Which stream the line came from: `"stdout"` or `"stderr"`.
+    stream: &'static str,
+    /// Parsed severity token (INFO/WARN/ERROR/DEBUG).
+    level: String,
+    /// The trimmed log line with the level token removed.
+    message: String,
+    /// Milliseconds since the Unix epoch, for ordering in the UI.
+    timestamp: u128,
+}
+
+/// Split a leading severity token off `line`, returning `(level, rest)`.
+///
+/// Recognizes `INFO`/`WARN`/`ERROR`/`DEBUG`, optionally wrapped in brackets
+/// and followed by a separator (e.g. `[INFO]`, `WARN:`). When no token is
+/// present the whole line is kept and `default` is used as the level.
+#[cfg(not(mobile))]
+fn parse_log_level(line: &str, default: &str) -> (String, String) {
+    let trimmed = line.trim_start();
+    let after_bracket = trimmed.trim_start_matches('[');
+    let head: String = after_bracket
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect();
+
+    let level = match head.to_ascii_uppercase().as_str() {
+        "INFO" | "WARN" | "WARNING" | "ERROR" | "DEBUG" => Some(head.to_ascii_uppercase()),
+        _ => None,
+    };
+
+    match level {
+        Some(level) => {
+            let rest = after_bracket[head.len()..].trim_start_matches(['[', ']', ':', '-', ' ']);
+            let level = if level == "WARNING" {
+                "WARN".to_string()
+            } else {
+                level
+            };
+            (level, rest.to_string())
+        }
+        None => (default.to_string(), trimmed.to_string()),
+    }
+}
+
+/// Milliseconds since the Unix epoch, or `0` if the clock is before it.
+#[cfg(not(mobile))]
+fn now_millis() -> u128 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0)
+}
 
 #[cfg(not(mobile))]
 fn kill_sidecar(app: &tauri::AppHandle) {
-    if let Ok(mut guard) = app.state::<SidecarState>().0.lock() {
+    if let Ok(mut guard) = app.state::<SidecarState>().child.lock() {
         if let Some(child) = guard.take() {
             #[cfg(windows)]
             {
@@ -38,76 +149,400 @@ fn kill_sidecar(app: &tauri::AppHandle) {
     }
 }
 
+/// Build the sidecar command with the standard environment and spawn it,
+/// returning its event receiver. The host/port and log configuration here are
+/// the single source of truth for the spawned process.
+#[cfg(not(mobile))]
+fn build_and_spawn(
+    app: &tauri::AppHandle,
+) -> Result<
+    (
+        tauri::async_runtime::Receiver<tauri_plugin_shell::process::CommandEvent>,
+        CommandChild,
+    ),
+    String,
+> {
+    let mut sidecar_log_path: Option<std::path::PathBuf> = None;
+    if let Ok(data_dir) = app.path().app_data_dir() {
+        let _ = fs::create_dir_all(&data_dir);
+        sidecar_log_path = Some(data_dir.join("tiptune-sidecar.log"));
+    }
+
+    // Record the path so diagnostics commands can read the log later.
+    if let Ok(mut guard) = app.state::<SidecarState>().log_path.lock() {
+        guard.clone_from(&sidecar_log_path);
+    }
+
+    let sidecar_command = app
+        .shell()
+        .sidecar("TipTune")
+        .map_err(|e| e.to_string())?
+        .env("TIPTUNE_PARENT_PID", std::process::id().to_string())
+        .env("TIPTUNE_WEB_HOST", SIDECAR_HOST)
+        .env("TIPTUNE_WEB_PORT", SIDECAR_PORT.to_string());
+
+    let mut sidecar_command = if env::var("TIPTUNE_LOG_LEVEL").is_err() {
+        sidecar_command.env("TIPTUNE_LOG_LEVEL", "INFO")
+    } else {
+        sidecar_command
+    };
+
+    // In `tauri dev` the CLI watches the project directory.
+    // If the sidecar writes logs into the repo, it can trigger an infinite rebuild/restart loop.
+    // In debug builds, always force the sidecar log file into the app data dir.
+    if env::var("TIPTUNE_DEFAULT_LOG_PATH").is_err() {
+        if let Some(p) = &sidecar_log_path {
+            sidecar_command =
+                sidecar_command.env("TIPTUNE_DEFAULT_LOG_PATH", p.to_string_lossy().to_string());
+        }
+    }
+
+    sidecar_command.spawn().map_err(|e| e.to_string())
+}
+
+/// Poll the sidecar's TCP port until it accepts a connection, then emit a
+/// `sidecar-ready` event. If it never comes up within [`READINESS_TIMEOUT_MS`]
+/// a `sidecar-failed` event is emitted instead.
+///
+/// `my_generation` pins this probe to the spawn it was started for; if
+/// [`SidecarState::generation`] has since moved on (a crash-loop respawn, or a
+/// manual [`restart_sidecar`] while this probe is still polling), it bails
+/// without emitting so a stale probe can't signal readiness for a child that
+/// is no longer current.
+#[cfg(not(mobile))]
+fn probe_readiness(app: &tauri::AppHandle, my_generation: u64) {
+    use std::net::{TcpStream, ToSocketAddrs};
+
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let deadline = now_millis() + READINESS_TIMEOUT_MS;
+        loop {
+            let connected = (SIDECAR_HOST, SIDECAR_PORT)
+                .to_socket_addrs()
+                .ok()
+                .and_then(|mut addrs| addrs.next())
+                .map(|addr| {
+                    TcpStream::connect_timeout(
+                        &addr,
+                        std::time::Duration::from_millis(READINESS_INTERVAL_MS),
+                    )
+                    .is_ok()
+                })
+                .unwrap_or(false);
+
+            let state = app_handle.state::<SidecarState>();
+            if state.generation.load(Ordering::SeqCst) != my_generation {
+                return;
+            }
+
+            if connected {
+                let _ = app_handle.emit("sidecar-ready", ());
+                return;
+            }
+
+            if now_millis() >= deadline {
+                let _ = app_handle.emit("sidecar-failed", ());
+                return;
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(READINESS_INTERVAL_MS)).await;
+        }
+    });
+}
+
+/// Spawn the TipTune sidecar and supervise it.
+///
+/// Stores the child in [`SidecarState`], forwards each output line to the
+/// webview as a `sidecar-log` event, and — when the child terminates
+/// unexpectedly (i.e. [`SidecarState::shutting_down`] is `false`) — respawns
+/// it after an exponentially backing-off delay (capped at
+/// [`SUPERVISOR_MAX_BACKOFF_MS`]). The backoff resets once the child has
+/// stayed up for [`SUPERVISOR_STABLE_MS`], and the supervisor gives up after
+/// [`SUPERVISOR_MAX_ATTEMPTS`] consecutive failures.
+///
+/// Safe to call repeatedly (e.g. on restart) as long as [`SidecarState`] is
+/// already managed; each call supersedes any previous supervisor task.
+#[cfg(not(mobile))]
+fn spawn_sidecar(app: &tauri::AppHandle) -> Result<(), String> {
+    use tauri_plugin_shell::process::CommandEvent;
+
+    let state = app.state::<SidecarState>();
+    state.shutting_down.store(false, Ordering::SeqCst);
+    let mut my_generation = state.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+    let (mut rx, child) = build_and_spawn(app)?;
+    if let Ok(mut guard) = state.child.lock() {
+        *guard = Some(child);
+    }
+    probe_readiness(app, my_generation);
+
+    let app_handle = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let mut backoff_ms = SUPERVISOR_MIN_BACKOFF_MS;
+        let mut attempt: u32 = 0;
+
+        loop {
+            let started_at = now_millis();
+
+            while let Some(event) = rx.recv().await {
+                match event {
+                    CommandEvent::Stdout(line) => {
+                        let s = String::from_utf8_lossy(&line);
+                        let s = s.trim_end_matches(&['\r', '\n'][..]);
+                        println!("[sidecar stdout] {}", s);
+                        let (level, message) = parse_log_level(s, "INFO");
+                        let _ = app_handle.emit(
+                            "sidecar-log",
+                            SidecarLogEvent {
+                                stream: "stdout",
+                                level,
+                                message,
+                                timestamp: now_millis(),
+                            },
+                        );
+                    }
+                    CommandEvent::Stderr(line) => {
+                        let s = String::from_utf8_lossy(&line);
+                        let s = s.trim_end_matches(&['\r', '\n'][..]);
+                        eprintln!("[sidecar stderr] {}", s);
+                        let (level, message) = parse_log_level(s, "ERROR");
+                        let _ = app_handle.emit(
+                            "sidecar-log",
+                            SidecarLogEvent {
+                                stream: "stderr",
+                                level,
+                                message,
+                                timestamp: now_millis(),
+                            },
+                        );
+                    }
+                    CommandEvent::Error(err) => {
+                        eprintln!("[sidecar error] {}", err);
+                    }
+                    CommandEvent::Terminated(payload) => {
+                        eprintln!("[sidecar terminated] {:?}", payload);
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+
+            let state = app_handle.state::<SidecarState>();
+
+            // The child this supervisor owns has exited: clear its slot so
+            // `start_sidecar` doesn't mistake it for a running one. The
+            // generation is checked under the lock so a newer spawn's child is
+            // never cleared.
+            if let Ok(mut guard) = state.child.lock() {
+                if state.generation.load(Ordering::SeqCst) == my_generation {
+                    *guard = None;
+                }
+            }
+
+            // A newer spawn superseded this supervisor, or we're shutting
+            // down on purpose: clean up and stop — don't restart.
+            if state.generation.load(Ordering::SeqCst) != my_generation
+                || state.shutting_down.load(Ordering::SeqCst)
+            {
+                break;
+            }
+
+            // The child had been up long enough to be considered healthy, so
+            // treat this as a fresh failure sequence.
+            if now_millis().saturating_sub(started_at) >= SUPERVISOR_STABLE_MS {
+                backoff_ms = SUPERVISOR_MIN_BACKOFF_MS;
+                attempt = 0;
+            }
+
+            attempt += 1;
+            if attempt > SUPERVISOR_MAX_ATTEMPTS {
+                eprintln!(
+                    "[sidecar supervisor] giving up after {} attempts",
+                    SUPERVISOR_MAX_ATTEMPTS
+                );
+                let _ = app_handle.emit("sidecar-failed", attempt);
+                break;
+            }
+
+            let _ = app_handle.emit("sidecar-restarting", attempt);
+            tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
+            backoff_ms = (backoff_ms * 2).min(SUPERVISOR_MAX_BACKOFF_MS);
+
+            // Superseded while we slept? Bail before spawning a duplicate.
+            if state.generation.load(Ordering::SeqCst) != my_generation
+                || state.shutting_down.load(Ordering::SeqCst)
+            {
+                break;
+            }
+
+            // Bump the generation for this internal retry too, not just for
+            // external `spawn_sidecar` calls, so the probe started for the
+            // previous (crashed) attempt is invalidated rather than racing
+            // the new one.
+            my_generation = state.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+            match build_and_spawn(&app_handle) {
+                Ok((new_rx, child)) => {
+                    rx = new_rx;
+                    if let Ok(mut guard) = state.child.lock() {
+                        *guard = Some(child);
+                    }
+                    probe_readiness(&app_handle, my_generation);
+                }
+                Err(err) => {
+                    // The stale receiver yields `None` immediately, so the
+                    // next loop iteration backs off and retries the spawn.
+                    eprintln!("[sidecar supervisor] respawn failed: {}", err);
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Spawn the sidecar if it isn't already running. A no-op otherwise.
+#[cfg(not(mobile))]
+#[tauri::command]
+fn start_sidecar(app: tauri::AppHandle) -> Result<(), String> {
+    let running = app
+        .state::<SidecarState>()
+        .child
+        .lock()
+        .map(|guard| guard.is_some())
+        .unwrap_or(false);
+    if running {
+        return Ok(());
+    }
+    spawn_sidecar(&app)
+}
+
+/// Kill the running sidecar, if any. Marks the teardown as intentional so the
+/// supervisor does not restart it.
+#[cfg(not(mobile))]
+#[tauri::command]
+fn stop_sidecar(app: tauri::AppHandle) -> Result<(), String> {
+    app.state::<SidecarState>()
+        .shutting_down
+        .store(true, Ordering::SeqCst);
+    kill_sidecar(&app);
+    Ok(())
+}
+
+/// Stop the sidecar and spawn a fresh one.
+#[cfg(not(mobile))]
+#[tauri::command]
+fn restart_sidecar(app: tauri::AppHandle) -> Result<(), String> {
+    // Mark the teardown as intentional before killing, mirroring
+    // `stop_sidecar`: the old supervisor's `Terminated` handler checks this
+    // flag, and without it set first, scheduling could let the old supervisor
+    // observe the termination before `spawn_sidecar` bumps the generation,
+    // misreading this restart as an unexpected crash.
+    app.state::<SidecarState>()
+        .shutting_down
+        .store(true, Ordering::SeqCst);
+    kill_sidecar(&app);
+    // `spawn_sidecar` clears `shutting_down`, bumps the generation so the old
+    // supervisor exits, and starts a fresh supervised child.
+    spawn_sidecar(&app)
+}
+
+/// Absolute path of the sidecar log file, for "reveal log file" actions.
+#[cfg(not(mobile))]
+#[tauri::command]
+fn get_sidecar_log_path(app: tauri::AppHandle) -> Result<String, String> {
+    app.state::<SidecarState>()
+        .log_path
+        .lock()
+        .map_err(|e| e.to_string())?
+        .as_ref()
+        .map(|p| p.to_string_lossy().to_string())
+        .ok_or_else(|| "sidecar log path is not available".to_string())
+}
+
+/// Return the last `lines` lines of the sidecar log in forward order.
+///
+/// Reads backwards from the end of the file in fixed-size chunks so large
+/// logs don't have to be loaded into memory, collecting newline boundaries
+/// until `lines` lines (or the start of the file) are reached.
+#[cfg(not(mobile))]
+#[tauri::command]
+fn get_sidecar_log_tail(app: tauri::AppHandle, lines: usize) -> Result<String, String> {
+    let path = app
+        .state::<SidecarState>()
+        .log_path
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clone()
+        .ok_or_else(|| "sidecar log path is not available".to_string())?;
+
+    tail_lines(&path, lines).map_err(|e| e.to_string())
+}
+
+/// Read the last `lines` lines of `path` without loading the whole file.
+#[cfg(not(mobile))]
+fn tail_lines(path: &std::path::Path, lines: usize) -> std::io::Result<String> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    if lines == 0 {
+        return Ok(String::new());
+    }
+
+    const CHUNK: usize = 8 * 1024;
+
+    let mut file = fs::File::open(path)?;
+    let mut pos = file.seek(SeekFrom::End(0))?;
+    let mut buf: Vec<u8> = Vec::new();
+    // Count of line terminators seen; we need `lines` of them beyond the final
+    // one to capture `lines` full lines.
+    let mut newlines = 0usize;
+
+    while pos > 0 && newlines <= lines {
+        let read_size = std::cmp::min(CHUNK as u64, pos);
+        pos -= read_size;
+        file.seek(SeekFrom::Start(pos))?;
+
+        let mut chunk = vec![0u8; read_size as usize];
+        file.read_exact(&mut chunk)?;
+
+        // Prepend the chunk so `buf` stays in forward order.
+        chunk.extend_from_slice(&buf);
+        buf = chunk;
+
+        newlines = buf.iter().filter(|&&b| b == b'\n').count();
+    }
+
+    let text = String::from_utf8_lossy(&buf);
+    // Keep the last `lines` lines, ignoring a trailing newline.
+    let trimmed = text.strip_suffix('\n').unwrap_or(&text);
+    let collected: Vec<&str> = trimmed.lines().collect();
+    let start = collected.len().saturating_sub(lines);
+    Ok(collected[start..].join("\n"))
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    let app = tauri::Builder::default()
+    let builder = tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
-        .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_shell::init());
+
+    #[cfg(not(mobile))]
+    let builder = builder.invoke_handler(tauri::generate_handler![
+        start_sidecar,
+        stop_sidecar,
+        restart_sidecar,
+        get_sidecar_log_path,
+        get_sidecar_log_tail
+    ]);
+
+    let app = builder
         .setup(|app| {
             #[cfg(not(mobile))]
             {
-                use tauri_plugin_shell::process::CommandEvent;
-
-                let mut sidecar_log_path: Option<std::path::PathBuf> = None;
-                if let Ok(data_dir) = app.path().app_data_dir() {
-                    let _ = fs::create_dir_all(&data_dir);
-                    sidecar_log_path = Some(data_dir.join("tiptune-sidecar.log"));
-                }
-
-                let sidecar_command = app
-                    .shell()
-                    .sidecar("TipTune")?
-                    .env("TIPTUNE_PARENT_PID", std::process::id().to_string())
-                    .env("TIPTUNE_WEB_HOST", "127.0.0.1")
-                    .env("TIPTUNE_WEB_PORT", "8765");
-
-                let mut sidecar_command = if env::var("TIPTUNE_LOG_LEVEL").is_err() {
-                    sidecar_command.env("TIPTUNE_LOG_LEVEL", "INFO")
-                } else {
-                    sidecar_command
-                };
-
-                // In `tauri dev` the CLI watches the project directory.
-                // If the sidecar writes logs into the repo, it can trigger an infinite rebuild/restart loop.
-                // In debug builds, always force the sidecar log file into the app data dir.
-                if env::var("TIPTUNE_DEFAULT_LOG_PATH").is_err() {
-                    if let Some(p) = &sidecar_log_path {
-                        sidecar_command = sidecar_command
-                            .env("TIPTUNE_DEFAULT_LOG_PATH", p.to_string_lossy().to_string());
-                    }
-                }
-
-                let (mut rx, child) = sidecar_command.spawn()?;
-
-                app.manage(SidecarState(Mutex::new(Some(child))));
-                let app_handle = app.handle().clone();
-
-                tauri::async_runtime::spawn(async move {
-                    while let Some(event) = rx.recv().await {
-                        match event {
-                            CommandEvent::Stdout(line) => {
-                                let s = String::from_utf8_lossy(&line);
-                                let s = s.trim_end_matches(&['\r', '\n'][..]);
-                                println!("[sidecar stdout] {}", s);
-                            }
-                            CommandEvent::Stderr(line) => {
-                                let s = String::from_utf8_lossy(&line);
-                                let s = s.trim_end_matches(&['\r', '\n'][..]);
-                                eprintln!("[sidecar stderr] {}", s);
-                            }
-                            CommandEvent::Error(err) => {
-                                eprintln!("[sidecar error] {}", err);
-                            }
-                            CommandEvent::Terminated(payload) => {
-                                eprintln!("[sidecar terminated] {:?}", payload);
-                                kill_sidecar(&app_handle);
-                                break;
-                            }
-                            _ => {}
-                        }
-                    }
-                });
+                app.manage(SidecarState::new());
+                spawn_sidecar(app.handle())?;
             }
 
             Ok(())
@@ -116,8 +551,13 @@ pub fn run() {
             #[cfg(not(mobile))]
             {
                 if matches!(event, WindowEvent::CloseRequested { .. }) {
-                    kill_sidecar(&window.app_handle());
-                    window.app_handle().exit(0);
+                    let app_handle = window.app_handle();
+                    app_handle
+                        .state::<SidecarState>()
+                        .shutting_down
+                        .store(true, Ordering::SeqCst);
+                    kill_sidecar(app_handle);
+                    app_handle.exit(0);
                 }
             }
         })
@@ -128,8 +568,98 @@ pub fn run() {
         #[cfg(not(mobile))]
         {
             if matches!(event, RunEvent::ExitRequested { .. } | RunEvent::Exit) {
+                app_handle
+                    .state::<SidecarState>()
+                    .shutting_down
+                    .store(true, Ordering::SeqCst);
                 kill_sidecar(app_handle);
             }
         }
     });
 }
+
+#[cfg(all(test, not(mobile)))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_log_level_strips_brackets() {
+        let (level, message) = parse_log_level("[INFO] msg", "INFO");
+        assert_eq!(level, "INFO");
+        assert_eq!(message, "msg");
+    }
+
+    #[test]
+    fn parse_log_level_colon_separator() {
+        let (level, message) = parse_log_level("WARN: msg", "INFO");
+        assert_eq!(level, "WARN");
+        assert_eq!(message, "msg");
+    }
+
+    #[test]
+    fn parse_log_level_normalizes_warning_with_dash_separator() {
+        let (level, message) = parse_log_level("WARNING - msg", "INFO");
+        assert_eq!(level, "WARN");
+        assert_eq!(message, "msg");
+    }
+
+    #[test]
+    fn parse_log_level_falls_back_to_default_when_no_token() {
+        let (level, message) = parse_log_level("just a plain line", "ERROR");
+        assert_eq!(level, "ERROR");
+        assert_eq!(message, "just a plain line");
+    }
+
+    /// Writes `content` to a uniquely-named file under the OS temp dir and
+    /// returns its path; the caller is responsible for removing it.
+    fn write_temp_file(name: &str, content: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("tiptune-tail-lines-test-{}", name));
+        fs::write(&path, content).expect("failed to write temp file");
+        path
+    }
+
+    #[test]
+    fn tail_lines_reads_across_chunk_boundaries() {
+        // Each line is well under CHUNK (8KiB) but the file as a whole spans
+        // several chunks. The last 1000 lines take 10,000 bytes, more than one
+        // chunk, so the backward-read loop has to read a second chunk.
+        let lines: Vec<String> = (0..2000).map(|i| format!("line {}", i)).collect();
+        let content = lines.join("\n") + "\n";
+        let path = write_temp_file("multi-chunk", &content);
+
+        let tail = tail_lines(&path, 1000).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(tail, lines[1000..].join("\n"));
+    }
+
+    #[test]
+    fn tail_lines_handles_missing_trailing_newline() {
+        let path = write_temp_file("no-trailing-newline", "a\nb\nc");
+
+        let tail = tail_lines(&path, 2).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(tail, "b\nc");
+    }
+
+    #[test]
+    fn tail_lines_requesting_more_than_available_returns_whole_file() {
+        let path = write_temp_file("short-file", "a\nb\n");
+
+        let tail = tail_lines(&path, 100).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(tail, "a\nb");
+    }
+
+    #[test]
+    fn tail_lines_zero_returns_empty_string() {
+        let path = write_temp_file("zero-lines", "a\nb\nc\n");
+
+        let tail = tail_lines(&path, 0).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(tail, "");
+    }
+}